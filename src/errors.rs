@@ -0,0 +1,81 @@
+// Copyright 2015 The GeoRust Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//  http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+/// Errors that can occur while parsing or manipulating GeoJSON data.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Error {
+    /// The top-level `type` member was missing or was not a recognized
+    /// GeoJSON type.
+    GeoJsonUnknownType,
+    /// A GeoJSON value was expected to be a JSON object.
+    GeoJsonExpectedObject,
+    /// A `Feature`'s `geometry` member was present but not a valid geometry
+    /// object.
+    FeatureInvalidGeometryValue,
+    /// A `Feature`'s `id` member was present but was neither a string nor a
+    /// number.
+    FeatureInvalidIdentifierType,
+    /// A typed property getter (e.g. `property_f64`) was called for a key
+    /// whose value could not be coerced to the requested type.
+    PropertyTypeMismatch {
+        key: String,
+        expected: &'static str,
+    },
+    /// `distance_to` was called on a feature with no geometry, or a geometry
+    /// with no coordinates to derive a representative point from.
+    /// `nearest_to` and `sort_by_distance` do not return this error; they
+    /// order such features after every locatable one.
+    NoCoordinatesForDistanceQuery,
+    /// A `SortBy` expression did not match any recognized syntax.
+    InvalidSortSyntax { expr: String },
+    /// A `SortBy` expression used a reserved GeoJSON member name (e.g.
+    /// `geometry`) as an ordinary property key.
+    ReservedSortKeyword { name: String },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::GeoJsonUnknownType => {
+                write!(f, "encountered GeoJSON with an unknown or missing `type`")
+            }
+            Error::GeoJsonExpectedObject => {
+                write!(f, "encountered a GeoJSON value that was not a JSON object")
+            }
+            Error::FeatureInvalidGeometryValue => write!(
+                f,
+                "encountered an invalid `geometry` value on a `Feature`"
+            ),
+            Error::FeatureInvalidIdentifierType => {
+                write!(f, "encountered an invalid `id` value on a `Feature`")
+            }
+            Error::PropertyTypeMismatch { key, expected } => {
+                write!(f, "property `{key}` could not be read as {expected}")
+            }
+            Error::NoCoordinatesForDistanceQuery => write!(
+                f,
+                "feature has no geometry, or a geometry with no coordinates, to compute a distance from"
+            ),
+            Error::InvalidSortSyntax { expr } => write!(f, "invalid sort expression: `{expr}`"),
+            Error::ReservedSortKeyword { name } => write!(
+                f,
+                "`{name}` is a reserved keyword and cannot be used as a sort key"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
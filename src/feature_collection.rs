@@ -0,0 +1,93 @@
+// Copyright 2015 The GeoRust Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//  http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::convert::TryFrom;
+
+use crate::json::{Deserialize, Deserializer, JsonObject, Serialize, Serializer};
+use crate::serde_json::json;
+use crate::{util, Bbox, Error, Feature};
+
+/// A GeoJSON `FeatureCollection` object: an ordered list of `Feature`s plus
+/// the optional `bbox` and foreign members every GeoJSON object may carry.
+///
+/// [GeoJSON Format Specification § 3.3](https://tools.ietf.org/html/rfc7946#section-3.3)
+#[derive(Clone, Debug, PartialEq)]
+pub struct FeatureCollection {
+    pub bbox: Option<Bbox>,
+    pub features: Vec<Feature>,
+    pub foreign_members: Option<JsonObject>,
+}
+
+impl<'a> From<&'a FeatureCollection> for JsonObject {
+    fn from(fc: &'a FeatureCollection) -> JsonObject {
+        let mut map = JsonObject::new();
+        map.insert(String::from("type"), json!("FeatureCollection"));
+        map.insert(
+            String::from("features"),
+            serde_json::to_value(&fc.features).unwrap(),
+        );
+        if let Some(ref bbox) = fc.bbox {
+            map.insert(String::from("bbox"), serde_json::to_value(bbox).unwrap());
+        }
+        if let Some(ref foreign_members) = fc.foreign_members {
+            for (key, value) in foreign_members {
+                map.insert(key.to_owned(), value.to_owned());
+            }
+        }
+        map
+    }
+}
+
+impl TryFrom<JsonObject> for FeatureCollection {
+    type Error = Error;
+
+    fn try_from(mut object: JsonObject) -> Result<Self, Error> {
+        match &*util::expect_type(&mut object)? {
+            "FeatureCollection" => {
+                let features = object
+                    .remove("features")
+                    .ok_or(Error::GeoJsonUnknownType)?;
+                let features: Vec<Feature> =
+                    serde_json::from_value(features).map_err(|_| Error::GeoJsonUnknownType)?;
+                Ok(FeatureCollection {
+                    bbox: util::get_bbox(&mut object)?,
+                    features,
+                    foreign_members: util::get_foreign_members(object)?,
+                })
+            }
+            _ => Err(Error::GeoJsonUnknownType),
+        }
+    }
+}
+
+impl Serialize for FeatureCollection {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        JsonObject::from(self).serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for FeatureCollection {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        use serde::de::Error as SerdeError;
+
+        let val = JsonObject::deserialize(deserializer)?;
+        FeatureCollection::try_from(val).map_err(|e| D::Error::custom(e.to_string()))
+    }
+}
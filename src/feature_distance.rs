@@ -0,0 +1,222 @@
+// Copyright 2015 The GeoRust Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//  http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{Error, Feature, FeatureBase, FeatureCollection, Geometry, Position, Value};
+
+/// Mean earth radius in meters, matching the constant used by geosearch.
+const EARTH_RADIUS_METERS: f64 = 6_371_000.0;
+
+/// Great-circle distance in meters between two `[lng, lat]` positions,
+/// computed with the haversine formula.
+fn haversine_distance_meters(a: [f64; 2], b: [f64; 2]) -> f64 {
+    let [lon1, lat1] = a;
+    let [lon2, lat2] = b;
+
+    let dlat = (lat2 - lat1).to_radians();
+    let dlon = (lon2 - lon1).to_radians();
+    let h = (dlat / 2.0).sin().powi(2)
+        + lat1.to_radians().cos() * lat2.to_radians().cos() * (dlon / 2.0).sin().powi(2);
+    2.0 * EARTH_RADIUS_METERS * h.sqrt().min(1.0).asin()
+}
+
+/// Derive a representative `[lng, lat]` coordinate for a geometry: the
+/// position of a `Point`, or the arithmetic centroid of every position in
+/// the geometry otherwise.
+fn representative_coordinate<P: Position>(geometry: &Geometry<P>) -> Option<[f64; 2]> {
+    match &geometry.value {
+        Value::Point(position) => Some(xy(position)),
+        value => centroid(value),
+    }
+}
+
+fn xy<P: Position>(position: &P) -> [f64; 2] {
+    [position.x(), position.y()]
+}
+
+fn centroid<P: Position>(value: &Value<P>) -> Option<[f64; 2]> {
+    let mut sum = [0.0_f64; 2];
+    let mut count = 0usize;
+    collect_positions(value, &mut |position| {
+        sum[0] += position[0];
+        sum[1] += position[1];
+        count += 1;
+    });
+    if count == 0 {
+        None
+    } else {
+        Some([sum[0] / count as f64, sum[1] / count as f64])
+    }
+}
+
+fn collect_positions<P: Position>(value: &Value<P>, visit: &mut impl FnMut([f64; 2])) {
+    match value {
+        Value::Point(position) => visit(xy(position)),
+        Value::MultiPoint(positions) | Value::LineString(positions) => {
+            positions.iter().for_each(|p| visit(xy(p)));
+        }
+        Value::MultiLineString(lines) | Value::Polygon(lines) => {
+            lines.iter().flatten().for_each(|p| visit(xy(p)));
+        }
+        Value::MultiPolygon(polygons) => {
+            polygons
+                .iter()
+                .flatten()
+                .flatten()
+                .for_each(|p| visit(xy(p)));
+        }
+        Value::GeometryCollection(geometries) => {
+            for geometry in geometries {
+                collect_positions(&geometry.value, visit);
+            }
+        }
+    }
+}
+
+impl<P: Position> FeatureBase<P> {
+    /// The great-circle distance in meters from `point` (`[lng, lat]`) to
+    /// this feature's representative coordinate.
+    ///
+    /// The representative coordinate is the feature's `Point` position, or
+    /// the centroid of all positions for any other geometry type. Returns
+    /// `Err(Error::NoCoordinatesForDistanceQuery)` when the feature has no
+    /// geometry, or a geometry with no coordinates.
+    pub fn distance_to(&self, point: [f64; 2]) -> Result<f64, Error> {
+        let geometry = self
+            .geometry
+            .as_ref()
+            .ok_or(Error::NoCoordinatesForDistanceQuery)?;
+        let coordinate =
+            representative_coordinate(geometry).ok_or(Error::NoCoordinatesForDistanceQuery)?;
+        Ok(haversine_distance_meters(point, coordinate))
+    }
+}
+
+/// Order two features by ascending distance from `point` (`[lng, lat]`).
+/// Features with no computable distance (see [`FeatureBase::distance_to`])
+/// are treated as infinitely far and sort after every locatable feature.
+fn compare_by_distance(a: &Feature, b: &Feature, point: [f64; 2]) -> std::cmp::Ordering {
+    match (a.distance_to(point), b.distance_to(point)) {
+        (Ok(da), Ok(db)) => da.partial_cmp(&db).unwrap(),
+        (Ok(_), Err(_)) => std::cmp::Ordering::Less,
+        (Err(_), Ok(_)) => std::cmp::Ordering::Greater,
+        (Err(_), Err(_)) => std::cmp::Ordering::Equal,
+    }
+}
+
+impl FeatureCollection {
+    /// The `n` features nearest to `point` (`[lng, lat]`), nearest first.
+    ///
+    /// Features with no computable distance sort after every locatable
+    /// feature (same ordering as [`FeatureCollection::sort_by_distance`]),
+    /// so they only appear here if fewer than `n` features are locatable.
+    pub fn nearest_to(&self, point: [f64; 2], n: usize) -> Vec<&Feature> {
+        let mut ordered: Vec<&Feature> = self.features.iter().collect();
+        ordered.sort_by(|a, b| compare_by_distance(a, b, point));
+        ordered.into_iter().take(n).collect()
+    }
+
+    /// Sort features in place by ascending distance from `point` (`[lng,
+    /// lat]`). Features with no computable distance sort last, in their
+    /// original relative order.
+    pub fn sort_by_distance(&mut self, point: [f64; 2]) {
+        self.features.sort_by(|a, b| compare_by_distance(a, b, point));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Feature, FeatureCollection};
+
+    fn point_feature(lng: f64, lat: f64) -> Feature {
+        Feature {
+            geometry: Some(Geometry::new(Value::Point(vec![lng, lat]))),
+            properties: None,
+            bbox: None,
+            id: None,
+            foreign_members: None,
+        }
+    }
+
+    fn no_geometry_feature() -> Feature {
+        Feature {
+            geometry: None,
+            properties: None,
+            bbox: None,
+            id: None,
+            foreign_members: None,
+        }
+    }
+
+    #[test]
+    fn distance_to_is_zero_at_same_point() {
+        let feature = point_feature(18.0686, 59.3293);
+        assert_eq!(feature.distance_to([18.0686, 59.3293]).unwrap(), 0.0);
+    }
+
+    #[test]
+    fn distance_to_errors_without_geometry() {
+        let feature = no_geometry_feature();
+        assert_eq!(
+            feature.distance_to([0.0, 0.0]),
+            Err(Error::NoCoordinatesForDistanceQuery)
+        );
+    }
+
+    #[test]
+    fn nearest_to_orders_by_distance_and_excludes_missing_geometry() {
+        let fc = FeatureCollection {
+            bbox: None,
+            features: vec![
+                point_feature(10.0, 10.0),
+                point_feature(0.0, 0.0),
+                no_geometry_feature(),
+                point_feature(1.0, 1.0),
+            ],
+            foreign_members: None,
+        };
+        let nearest = fc.nearest_to([0.0, 0.0], 2);
+        assert_eq!(nearest.len(), 2);
+        assert_eq!(nearest[0].geometry, Some(Geometry::new(Value::Point(vec![0.0, 0.0]))));
+        assert_eq!(nearest[1].geometry, Some(Geometry::new(Value::Point(vec![1.0, 1.0]))));
+    }
+
+    #[test]
+    fn nearest_to_includes_unlocatable_features_when_fewer_than_n_are_locatable() {
+        let fc = FeatureCollection {
+            bbox: None,
+            features: vec![point_feature(0.0, 0.0), no_geometry_feature()],
+            foreign_members: None,
+        };
+        let nearest = fc.nearest_to([0.0, 0.0], 2);
+        assert_eq!(nearest.len(), 2);
+        assert!(nearest[1].geometry.is_none());
+    }
+
+    #[test]
+    fn sort_by_distance_puts_missing_geometry_last() {
+        let mut fc = FeatureCollection {
+            bbox: None,
+            features: vec![
+                point_feature(10.0, 10.0),
+                no_geometry_feature(),
+                point_feature(0.0, 0.0),
+            ],
+            foreign_members: None,
+        };
+        fc.sort_by_distance([0.0, 0.0]);
+        assert_eq!(fc.features[0].geometry, Some(Geometry::new(Value::Point(vec![0.0, 0.0]))));
+        assert!(fc.features[2].geometry.is_none());
+    }
+}
@@ -0,0 +1,299 @@
+// Copyright 2015 The GeoRust Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//  http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use crate::json::JsonValue;
+use crate::{Error, FeatureBase, FeatureCollection, Position};
+
+/// The inferred JSON type of a property value.
+///
+/// Analogous to an OGR field type: when a key is observed with more than one
+/// underlying JSON type across a `FeatureCollection`, the field widens
+/// (`Integer` to `Real`) or, if the types are incompatible, is reported as
+/// `Mixed`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FieldType {
+    Integer,
+    Real,
+    String,
+    Bool,
+    DateTime,
+    List,
+    Null,
+    Mixed,
+}
+
+/// The inferred definition of a single property key across a
+/// `FeatureCollection`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FieldSchema {
+    pub field_type: FieldType,
+    pub nullable: bool,
+}
+
+/// A field-by-field schema inferred from the `properties` of every `Feature`
+/// in a `FeatureCollection`, analogous to GDAL's OGR layer definition.
+///
+/// Fields are keyed by property name; a key missing from some feature's
+/// `properties` is treated as nullable, same as a key explicitly set to
+/// `null`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FeatureSchema {
+    pub fields: BTreeMap<String, FieldSchema>,
+}
+
+impl FeatureCollection {
+    /// Scan this collection's features and derive a `FeatureSchema`
+    /// describing the inferred type and nullability of each property key.
+    pub fn infer_schema(&self) -> FeatureSchema {
+        let mut fields: BTreeMap<String, FieldSchema> = BTreeMap::new();
+
+        for feature in &self.features {
+            let Some(properties) = feature.properties.as_ref() else {
+                continue;
+            };
+            for (key, value) in properties {
+                let observed = field_type_of(value);
+                fields
+                    .entry(key.clone())
+                    .and_modify(|field| {
+                        field.field_type = widen(field.field_type, observed);
+                        if observed == FieldType::Null {
+                            field.nullable = true;
+                        }
+                    })
+                    .or_insert(FieldSchema {
+                        field_type: observed,
+                        nullable: observed == FieldType::Null,
+                    });
+            }
+        }
+
+        let keys: Vec<String> = fields.keys().cloned().collect();
+        for feature in &self.features {
+            let has_key = |key: &str| {
+                feature
+                    .properties
+                    .as_ref()
+                    .map(|p| p.contains_key(key))
+                    .unwrap_or(false)
+            };
+            for key in &keys {
+                if !has_key(key) {
+                    fields.get_mut(key).unwrap().nullable = true;
+                }
+            }
+        }
+
+        FeatureSchema { fields }
+    }
+}
+
+fn field_type_of(value: &JsonValue) -> FieldType {
+    match value {
+        JsonValue::Null => FieldType::Null,
+        JsonValue::Bool(_) => FieldType::Bool,
+        JsonValue::Number(n) => {
+            if n.is_i64() || n.is_u64() {
+                FieldType::Integer
+            } else {
+                FieldType::Real
+            }
+        }
+        JsonValue::String(s) => {
+            if looks_like_datetime(s) {
+                FieldType::DateTime
+            } else {
+                FieldType::String
+            }
+        }
+        JsonValue::Array(_) => FieldType::List,
+        JsonValue::Object(_) => FieldType::Mixed,
+    }
+}
+
+fn widen(a: FieldType, b: FieldType) -> FieldType {
+    use FieldType::*;
+
+    match (a, b) {
+        (x, y) if x == y => x,
+        (Null, y) => y,
+        (x, Null) => x,
+        (Integer, Real) | (Real, Integer) => Real,
+        _ => Mixed,
+    }
+}
+
+/// A conservative check for RFC 3339 date/date-time strings, e.g.
+/// `"2024-01-02"` or `"2024-01-02T03:04:05Z"`.
+fn looks_like_datetime(s: &str) -> bool {
+    let bytes = s.as_bytes();
+    if bytes.len() < 10 || bytes[4] != b'-' || bytes[7] != b'-' {
+        return false;
+    }
+    let is_digit = |i: usize| bytes[i].is_ascii_digit();
+    let date_is_numeric = [0, 1, 2, 3, 5, 6, 8, 9].iter().all(|&i| is_digit(i));
+    date_is_numeric && (bytes.len() == 10 || bytes[10] == b'T' || bytes[10] == b' ')
+}
+
+impl<P: Position> FeatureBase<P> {
+    /// Read a property as an `f64`, coercing from a JSON integer or float.
+    ///
+    /// Returns `Ok(None)` when `properties` is `None` or `key` is absent, and
+    /// `Err(Error::PropertyTypeMismatch)` when the value is present but not a
+    /// number.
+    pub fn property_f64(&self, key: &str) -> Result<Option<f64>, Error> {
+        match self.property_value(key) {
+            None => Ok(None),
+            Some(value) => value
+                .as_f64()
+                .map(Some)
+                .ok_or_else(|| Error::PropertyTypeMismatch {
+                    key: key.to_string(),
+                    expected: "number",
+                }),
+        }
+    }
+
+    /// Read a property as an `i64`. See [`FeatureBase::property_f64`].
+    pub fn property_i64(&self, key: &str) -> Result<Option<i64>, Error> {
+        match self.property_value(key) {
+            None => Ok(None),
+            Some(value) => value
+                .as_i64()
+                .map(Some)
+                .ok_or_else(|| Error::PropertyTypeMismatch {
+                    key: key.to_string(),
+                    expected: "integer",
+                }),
+        }
+    }
+
+    /// Read a property as a `&str`. See [`FeatureBase::property_f64`].
+    pub fn property_str(&self, key: &str) -> Result<Option<&str>, Error> {
+        match self.property_value(key) {
+            None => Ok(None),
+            Some(value) => value
+                .as_str()
+                .map(Some)
+                .ok_or_else(|| Error::PropertyTypeMismatch {
+                    key: key.to_string(),
+                    expected: "string",
+                }),
+        }
+    }
+
+    fn property_value(&self, key: &str) -> Option<&JsonValue> {
+        self.properties.as_ref().and_then(|p| p.get(key))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Feature, FeatureCollection};
+
+    fn feature_with(properties: crate::json::JsonObject) -> Feature {
+        Feature {
+            geometry: None,
+            properties: Some(properties),
+            bbox: None,
+            id: None,
+            foreign_members: None,
+        }
+    }
+
+    fn props(pairs: &[(&str, JsonValue)]) -> crate::json::JsonObject {
+        let mut map = crate::json::JsonObject::new();
+        for (key, value) in pairs {
+            map.insert(key.to_string(), value.clone());
+        }
+        map
+    }
+
+    #[test]
+    fn infers_integer_field() {
+        let fc = FeatureCollection {
+            bbox: None,
+            features: vec![
+                feature_with(props(&[("population", serde_json::json!(10))])),
+                feature_with(props(&[("population", serde_json::json!(20))])),
+            ],
+            foreign_members: None,
+        };
+        let schema = fc.infer_schema();
+        let field = schema.fields.get("population").unwrap();
+        assert_eq!(field.field_type, FieldType::Integer);
+        assert!(!field.nullable);
+    }
+
+    #[test]
+    fn widens_integer_and_real_to_real() {
+        let fc = FeatureCollection {
+            bbox: None,
+            features: vec![
+                feature_with(props(&[("score", serde_json::json!(10))])),
+                feature_with(props(&[("score", serde_json::json!(1.5))])),
+            ],
+            foreign_members: None,
+        };
+        let schema = fc.infer_schema();
+        assert_eq!(
+            schema.fields.get("score").unwrap().field_type,
+            FieldType::Real
+        );
+    }
+
+    #[test]
+    fn flags_mixed_types_and_missing_as_nullable() {
+        let fc = FeatureCollection {
+            bbox: None,
+            features: vec![
+                feature_with(props(&[("name", serde_json::json!("a"))])),
+                feature_with(props(&[("name", serde_json::json!(1))])),
+                feature_with(props(&[])),
+            ],
+            foreign_members: None,
+        };
+        let schema = fc.infer_schema();
+        let field = schema.fields.get("name").unwrap();
+        assert_eq!(field.field_type, FieldType::Mixed);
+        assert!(field.nullable);
+    }
+
+    #[test]
+    fn property_f64_coerces_from_integer() {
+        let feature = feature_with(props(&[("count", serde_json::json!(3))]));
+        assert_eq!(feature.property_f64("count").unwrap(), Some(3.0));
+    }
+
+    #[test]
+    fn property_str_reports_type_mismatch() {
+        let feature = feature_with(props(&[("count", serde_json::json!(3))]));
+        match feature.property_str("count") {
+            Err(Error::PropertyTypeMismatch { key, expected }) => {
+                assert_eq!(key, "count");
+                assert_eq!(expected, "string");
+            }
+            other => panic!("expected PropertyTypeMismatch, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn property_missing_key_is_none() {
+        let feature = feature_with(props(&[]));
+        assert_eq!(feature.property_str("missing").unwrap(), None);
+    }
+}
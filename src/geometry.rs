@@ -0,0 +1,224 @@
+// Copyright 2015 The GeoRust Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//  http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::convert::TryFrom;
+
+use crate::json::{Deserialize, Deserializer, JsonObject, JsonValue, Serialize, Serializer};
+use crate::serde_json::json;
+use crate::{util, Bbox, Error};
+
+/// A single coordinate position backing a GeoJSON geometry.
+///
+/// `Vec<f64>` is the default and only position type most callers need;
+/// the trait exists so `Geometry`/`FeatureBase` can be generic over richer
+/// position representations.
+pub trait Position:
+    Clone + std::fmt::Debug + PartialEq + Serialize + for<'de> Deserialize<'de>
+{
+    fn x(&self) -> f64;
+    fn y(&self) -> f64;
+}
+
+impl Position for Vec<f64> {
+    fn x(&self) -> f64 {
+        self[0]
+    }
+
+    fn y(&self) -> f64 {
+        self[1]
+    }
+}
+
+/// The geometry kind and coordinates of a GeoJSON `Geometry` object.
+///
+/// [GeoJSON Format Specification § 3.1](https://tools.ietf.org/html/rfc7946#section-3.1)
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value<P: Position = Vec<f64>> {
+    Point(P),
+    MultiPoint(Vec<P>),
+    LineString(Vec<P>),
+    MultiLineString(Vec<Vec<P>>),
+    Polygon(Vec<Vec<P>>),
+    MultiPolygon(Vec<Vec<Vec<P>>>),
+    GeometryCollection(Vec<Geometry<P>>),
+}
+
+impl<P: Position> Value<P> {
+    fn type_name(&self) -> &'static str {
+        match self {
+            Value::Point(..) => "Point",
+            Value::MultiPoint(..) => "MultiPoint",
+            Value::LineString(..) => "LineString",
+            Value::MultiLineString(..) => "MultiLineString",
+            Value::Polygon(..) => "Polygon",
+            Value::MultiPolygon(..) => "MultiPolygon",
+            Value::GeometryCollection(..) => "GeometryCollection",
+        }
+    }
+
+    fn coordinates_json(&self) -> JsonValue {
+        match self {
+            Value::Point(position) => serde_json::to_value(position).unwrap(),
+            Value::MultiPoint(positions) | Value::LineString(positions) => {
+                serde_json::to_value(positions).unwrap()
+            }
+            Value::MultiLineString(lines) | Value::Polygon(lines) => {
+                serde_json::to_value(lines).unwrap()
+            }
+            Value::MultiPolygon(polygons) => serde_json::to_value(polygons).unwrap(),
+            Value::GeometryCollection(_) => JsonValue::Null,
+        }
+    }
+
+    fn from_type_and_coordinates(type_name: &str, coordinates: JsonValue) -> Result<Self, Error> {
+        let invalid = || Error::FeatureInvalidGeometryValue;
+        match type_name {
+            "Point" => Ok(Value::Point(
+                serde_json::from_value(coordinates).map_err(|_| invalid())?,
+            )),
+            "MultiPoint" => Ok(Value::MultiPoint(
+                serde_json::from_value(coordinates).map_err(|_| invalid())?,
+            )),
+            "LineString" => Ok(Value::LineString(
+                serde_json::from_value(coordinates).map_err(|_| invalid())?,
+            )),
+            "MultiLineString" => Ok(Value::MultiLineString(
+                serde_json::from_value(coordinates).map_err(|_| invalid())?,
+            )),
+            "Polygon" => Ok(Value::Polygon(
+                serde_json::from_value(coordinates).map_err(|_| invalid())?,
+            )),
+            "MultiPolygon" => Ok(Value::MultiPolygon(
+                serde_json::from_value(coordinates).map_err(|_| invalid())?,
+            )),
+            _ => Err(Error::GeoJsonUnknownType),
+        }
+    }
+}
+
+/// A GeoJSON `Geometry` object: a `Value` plus the optional `bbox` and
+/// foreign members every GeoJSON object may carry.
+///
+/// [GeoJSON Format Specification § 3.1](https://tools.ietf.org/html/rfc7946#section-3.1)
+#[derive(Clone, Debug, PartialEq)]
+pub struct Geometry<P: Position = Vec<f64>> {
+    pub value: Value<P>,
+    pub bbox: Option<Bbox>,
+    pub foreign_members: Option<JsonObject>,
+}
+
+impl<P: Position> Geometry<P> {
+    pub fn new(value: Value<P>) -> Self {
+        Geometry {
+            value,
+            bbox: None,
+            foreign_members: None,
+        }
+    }
+
+    pub fn from_json_object(object: JsonObject) -> Result<Self, Error> {
+        Self::try_from(object)
+    }
+
+    pub fn from_json_value(value: JsonValue) -> Result<Self, Error> {
+        match value {
+            JsonValue::Object(object) => Self::try_from(object),
+            _ => Err(Error::FeatureInvalidGeometryValue),
+        }
+    }
+}
+
+impl<'a, P: Position> From<&'a Geometry<P>> for JsonObject {
+    fn from(geometry: &'a Geometry<P>) -> JsonObject {
+        let mut map = JsonObject::new();
+        match &geometry.value {
+            Value::GeometryCollection(geometries) => {
+                map.insert(String::from("type"), json!("GeometryCollection"));
+                let geometries: Vec<JsonObject> =
+                    geometries.iter().map(JsonObject::from).collect();
+                map.insert(
+                    String::from("geometries"),
+                    serde_json::to_value(geometries).unwrap(),
+                );
+            }
+            value => {
+                map.insert(String::from("type"), json!(value.type_name()));
+                map.insert(String::from("coordinates"), value.coordinates_json());
+            }
+        }
+        if let Some(ref bbox) = geometry.bbox {
+            map.insert(String::from("bbox"), serde_json::to_value(bbox).unwrap());
+        }
+        if let Some(ref foreign_members) = geometry.foreign_members {
+            for (key, value) in foreign_members {
+                map.insert(key.to_owned(), value.to_owned());
+            }
+        }
+        map
+    }
+}
+
+impl<P: Position> TryFrom<JsonObject> for Geometry<P> {
+    type Error = Error;
+
+    fn try_from(mut object: JsonObject) -> Result<Self, Error> {
+        let type_name = util::expect_type(&mut object)?;
+        let bbox = util::get_bbox(&mut object)?;
+
+        let value = if type_name == "GeometryCollection" {
+            let geometries = object
+                .remove("geometries")
+                .ok_or(Error::FeatureInvalidGeometryValue)?;
+            let geometries: Vec<JsonValue> =
+                serde_json::from_value(geometries).map_err(|_| Error::FeatureInvalidGeometryValue)?;
+            let geometries = geometries
+                .into_iter()
+                .map(Geometry::from_json_value)
+                .collect::<Result<Vec<_>, _>>()?;
+            Value::GeometryCollection(geometries)
+        } else {
+            let coordinates = object
+                .remove("coordinates")
+                .ok_or(Error::FeatureInvalidGeometryValue)?;
+            Value::from_type_and_coordinates(&type_name, coordinates)?
+        };
+
+        Ok(Geometry {
+            value,
+            bbox,
+            foreign_members: util::get_foreign_members(object)?,
+        })
+    }
+}
+
+impl<P: Position> Serialize for Geometry<P> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        JsonObject::from(self).serialize(serializer)
+    }
+}
+
+impl<'de, P: Position> Deserialize<'de> for Geometry<P> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        use serde::de::Error as SerdeError;
+
+        let val = JsonObject::deserialize(deserializer)?;
+        Geometry::from_json_object(val).map_err(|e| D::Error::custom(e.to_string()))
+    }
+}
@@ -0,0 +1,25 @@
+// Copyright 2015 The GeoRust Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//  http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Re-exports of the `serde`/`serde_json` types used throughout the public
+//! API, so callers and the rest of this crate go through one path.
+
+pub use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// A JSON object, as used for GeoJSON `properties` and for the intermediate
+/// representation feature/geometry (de)serialization goes through.
+pub type JsonObject = serde_json::Map<String, serde_json::Value>;
+
+/// A single JSON value.
+pub type JsonValue = serde_json::Value;
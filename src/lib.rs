@@ -0,0 +1,98 @@
+// Copyright 2015 The GeoRust Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//  http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Reading and writing [GeoJSON](https://tools.ietf.org/html/rfc7946) data.
+
+use std::convert::TryFrom;
+use std::fmt;
+use std::str::FromStr;
+
+pub use serde_json;
+
+pub mod errors;
+pub mod feature;
+pub mod feature_collection;
+pub mod feature_distance;
+pub mod feature_schema;
+pub mod geometry;
+pub mod json;
+pub mod sort_by;
+pub(crate) mod util;
+
+pub use crate::errors::Error;
+pub use crate::feature_collection::FeatureCollection;
+pub use crate::geometry::{Geometry, Position, Value};
+pub use crate::sort_by::{Order, SortBy};
+use crate::json::{JsonObject, JsonValue};
+
+/// A `[min_x, min_y, max_x, max_y]`-style bounding box (or its 3D analogue).
+pub type Bbox = Vec<f64>;
+
+/// A GeoJSON `Feature` object, generic over its position type.
+///
+/// [GeoJSON Format Specification § 3.2](https://tools.ietf.org/html/rfc7946#section-3.2)
+#[derive(Clone, Debug, PartialEq)]
+pub struct FeatureBase<P: Position = Vec<f64>> {
+    pub bbox: Option<Bbox>,
+    pub geometry: Option<Geometry<P>>,
+    pub id: Option<feature::Id>,
+    pub properties: Option<JsonObject>,
+    pub foreign_members: Option<JsonObject>,
+}
+
+/// A GeoJSON `Feature` over `Vec<f64>` positions.
+pub type Feature = FeatureBase<Vec<f64>>;
+
+/// Any top-level GeoJSON value: a bare geometry, a `Feature`, or a
+/// `FeatureCollection`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum GeoJson {
+    Geometry(Geometry),
+    Feature(Feature),
+    FeatureCollection(FeatureCollection),
+}
+
+impl TryFrom<JsonValue> for GeoJson {
+    type Error = Error;
+
+    fn try_from(value: JsonValue) -> Result<Self, Error> {
+        let object = match value {
+            JsonValue::Object(object) => object,
+            _ => return Err(Error::GeoJsonExpectedObject),
+        };
+        match object.get("type").and_then(JsonValue::as_str) {
+            Some("Feature") => Ok(GeoJson::Feature(Feature::try_from(object)?)),
+            Some("FeatureCollection") => {
+                Ok(GeoJson::FeatureCollection(FeatureCollection::try_from(object)?))
+            }
+            Some(_) => Ok(GeoJson::Geometry(Geometry::try_from(object)?)),
+            None => Err(Error::GeoJsonUnknownType),
+        }
+    }
+}
+
+impl FromStr for GeoJson {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        let value: JsonValue = serde_json::from_str(s).map_err(|_| Error::GeoJsonExpectedObject)?;
+        GeoJson::try_from(value)
+    }
+}
+
+impl fmt::Display for Feature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        serde_json::to_string(self).map_err(|_| fmt::Error)?.fmt(f)
+    }
+}
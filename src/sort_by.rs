@@ -0,0 +1,251 @@
+// Copyright 2015 The GeoRust Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//  http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::Ordering;
+use std::str::FromStr;
+
+use crate::json::JsonValue;
+use crate::{Error, Feature, FeatureCollection};
+
+/// Property keys reserved by the GeoJSON spec; rejected as sort keys since
+/// they don't live under `properties`.
+const RESERVED_SORT_KEYWORDS: &[&str] = &["geometry", "type", "bbox", "id", "properties"];
+
+/// Ascending or descending order for a single [`SortBy`] rule.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Order {
+    Asc,
+    Desc,
+}
+
+/// A single ordering rule parsed from a human-friendly expression such as
+/// `"asc(population)"`, `"desc(name)"`, or the reserved `"geoPoint(lat,lng):asc"`
+/// form, which orders by distance from a `(lat, lng)` anchor.
+///
+/// See [`FeatureCollection::sort`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum SortBy {
+    Property { key: String, order: Order },
+    GeoPoint { lat: f64, lng: f64, order: Order },
+}
+
+impl FromStr for SortBy {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        let expr = s.trim();
+        let syntax_err = || Error::InvalidSortSyntax {
+            expr: expr.to_string(),
+        };
+
+        if let Some(rest) = expr.strip_prefix("geoPoint(") {
+            let (args, order) = split_call_and_order(rest).ok_or_else(syntax_err)?;
+            let (lat, lng) = args.split_once(',').ok_or_else(syntax_err)?;
+            let lat: f64 = lat.trim().parse().map_err(|_| syntax_err())?;
+            let lng: f64 = lng.trim().parse().map_err(|_| syntax_err())?;
+            return Ok(SortBy::GeoPoint { lat, lng, order });
+        }
+
+        let (order, rest) = if let Some(rest) = expr.strip_prefix("asc(") {
+            (Order::Asc, rest)
+        } else if let Some(rest) = expr.strip_prefix("desc(") {
+            (Order::Desc, rest)
+        } else {
+            return Err(syntax_err());
+        };
+
+        let key = rest.strip_suffix(')').ok_or_else(syntax_err)?.trim();
+        if key.is_empty() || key.contains(['(', ')', ',']) {
+            return Err(syntax_err());
+        }
+        if RESERVED_SORT_KEYWORDS.contains(&key) {
+            return Err(Error::ReservedSortKeyword {
+                name: key.to_string(),
+            });
+        }
+
+        Ok(SortBy::Property {
+            key: key.to_string(),
+            order,
+        })
+    }
+}
+
+/// Split `"lat,lng):asc"` into (`"lat,lng"`, `Order::Asc`).
+fn split_call_and_order(rest: &str) -> Option<(&str, Order)> {
+    let close = rest.find(')')?;
+    let (args, tail) = rest.split_at(close);
+    let order_str = tail.strip_prefix("):")?;
+    let order = match order_str {
+        "asc" => Order::Asc,
+        "desc" => Order::Desc,
+        _ => return None,
+    };
+    Some((args, order))
+}
+
+impl SortBy {
+    fn compare(&self, a: &Feature, b: &Feature) -> Ordering {
+        match self {
+            SortBy::Property { key, order } => compare_property(a, b, key, *order),
+            SortBy::GeoPoint { lat, lng, order } => {
+                let anchor = [*lng, *lat];
+                let ordering = match (a.distance_to(anchor), b.distance_to(anchor)) {
+                    (Ok(da), Ok(db)) => da.partial_cmp(&db).unwrap_or(Ordering::Equal),
+                    (Ok(_), Err(_)) => Ordering::Less,
+                    (Err(_), Ok(_)) => Ordering::Greater,
+                    (Err(_), Err(_)) => Ordering::Equal,
+                };
+                apply_order(ordering, *order)
+            }
+        }
+    }
+}
+
+fn apply_order(ordering: Ordering, order: Order) -> Ordering {
+    match order {
+        Order::Asc => ordering,
+        Order::Desc => ordering.reverse(),
+    }
+}
+
+/// Properties missing a key sort after those that have it, regardless of
+/// `order`, matching the "sort last" behavior used elsewhere for absent data.
+fn compare_property(a: &Feature, b: &Feature, key: &str, order: Order) -> Ordering {
+    fn value_of<'a>(feature: &'a Feature, key: &str) -> Option<&'a JsonValue> {
+        feature.properties.as_ref().and_then(|p| p.get(key))
+    }
+    match (value_of(a, key), value_of(b, key)) {
+        (None, None) => Ordering::Equal,
+        (None, Some(_)) => Ordering::Greater,
+        (Some(_), None) => Ordering::Less,
+        (Some(a), Some(b)) => apply_order(compare_json_values(a, b), order),
+    }
+}
+
+fn compare_json_values(a: &JsonValue, b: &JsonValue) -> Ordering {
+    if let (Some(a), Some(b)) = (a.as_f64(), b.as_f64()) {
+        a.partial_cmp(&b).unwrap_or(Ordering::Equal)
+    } else if let (Some(a), Some(b)) = (a.as_str(), b.as_str()) {
+        a.cmp(b)
+    } else {
+        a.to_string().cmp(&b.to_string())
+    }
+}
+
+impl FeatureCollection {
+    /// Sort features in place by applying each `SortBy` rule in order,
+    /// falling through to the next rule on ties.
+    pub fn sort(&mut self, rules: &[SortBy]) {
+        self.features.sort_by(|a, b| {
+            for rule in rules {
+                let ordering = rule.compare(a, b);
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+            }
+            Ordering::Equal
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Feature, FeatureCollection};
+
+    fn feature_with(pairs: &[(&str, JsonValue)]) -> Feature {
+        let mut properties = crate::json::JsonObject::new();
+        for (key, value) in pairs {
+            properties.insert(key.to_string(), value.clone());
+        }
+        Feature {
+            geometry: None,
+            properties: Some(properties),
+            bbox: None,
+            id: None,
+            foreign_members: None,
+        }
+    }
+
+    #[test]
+    fn parses_asc_and_desc() {
+        assert_eq!(
+            "asc(population)".parse::<SortBy>().unwrap(),
+            SortBy::Property {
+                key: "population".to_string(),
+                order: Order::Asc,
+            }
+        );
+        assert_eq!(
+            "desc(name)".parse::<SortBy>().unwrap(),
+            SortBy::Property {
+                key: "name".to_string(),
+                order: Order::Desc,
+            }
+        );
+    }
+
+    #[test]
+    fn parses_geo_point() {
+        assert_eq!(
+            "geoPoint(59.33,18.07):asc".parse::<SortBy>().unwrap(),
+            SortBy::GeoPoint {
+                lat: 59.33,
+                lng: 18.07,
+                order: Order::Asc,
+            }
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_syntax() {
+        match "asc(population".parse::<SortBy>() {
+            Err(Error::InvalidSortSyntax { expr }) => assert_eq!(expr, "asc(population"),
+            other => panic!("expected InvalidSortSyntax, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn rejects_reserved_keywords() {
+        match "asc(geometry)".parse::<SortBy>() {
+            Err(Error::ReservedSortKeyword { name }) => assert_eq!(name, "geometry"),
+            other => panic!("expected ReservedSortKeyword, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn sort_applies_rules_in_order() {
+        let mut fc = FeatureCollection {
+            bbox: None,
+            features: vec![
+                feature_with(&[("group", serde_json::json!("b")), ("rank", serde_json::json!(2))]),
+                feature_with(&[("group", serde_json::json!("a")), ("rank", serde_json::json!(1))]),
+                feature_with(&[("group", serde_json::json!("a")), ("rank", serde_json::json!(0))]),
+            ],
+            foreign_members: None,
+        };
+        let rules = vec![
+            "asc(group)".parse::<SortBy>().unwrap(),
+            "asc(rank)".parse::<SortBy>().unwrap(),
+        ];
+        fc.sort(&rules);
+        let ranks: Vec<_> = fc
+            .features
+            .iter()
+            .map(|f| f.property_i64("rank").unwrap().unwrap())
+            .collect();
+        assert_eq!(ranks, vec![0, 1, 2]);
+    }
+}
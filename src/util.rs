@@ -0,0 +1,70 @@
+// Copyright 2015 The GeoRust Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//  http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Shared helpers for pulling the common GeoJSON object members (`type`,
+//! `geometry`, `properties`, `id`, `bbox`, foreign members) out of a
+//! [`JsonObject`] while it's being converted into a typed value.
+
+use crate::feature::Id;
+use crate::json::{JsonObject, JsonValue};
+use crate::{Bbox, Error, Geometry, Position};
+
+pub(crate) fn expect_type(object: &mut JsonObject) -> Result<String, Error> {
+    match object.remove("type") {
+        Some(JsonValue::String(type_name)) => Ok(type_name),
+        _ => Err(Error::GeoJsonUnknownType),
+    }
+}
+
+pub(crate) fn get_geometry<P: Position>(
+    object: &mut JsonObject,
+) -> Result<Option<Geometry<P>>, Error> {
+    match object.remove("geometry") {
+        None | Some(JsonValue::Null) => Ok(None),
+        Some(value) => Geometry::from_json_value(value).map(Some),
+    }
+}
+
+pub(crate) fn get_properties(object: &mut JsonObject) -> Result<Option<JsonObject>, Error> {
+    match object.remove("properties") {
+        Some(JsonValue::Object(properties)) => Ok(Some(properties)),
+        _ => Ok(None),
+    }
+}
+
+pub(crate) fn get_id(object: &mut JsonObject) -> Result<Option<Id>, Error> {
+    match object.remove("id") {
+        None => Ok(None),
+        Some(JsonValue::String(s)) => Ok(Some(Id::String(s))),
+        Some(JsonValue::Number(n)) => Ok(Some(Id::Number(n))),
+        Some(_) => Err(Error::FeatureInvalidIdentifierType),
+    }
+}
+
+pub(crate) fn get_bbox(object: &mut JsonObject) -> Result<Option<Bbox>, Error> {
+    match object.remove("bbox") {
+        None | Some(JsonValue::Null) => Ok(None),
+        Some(value) => {
+            serde_json::from_value(value).map(Some).map_err(|_| Error::GeoJsonExpectedObject)
+        }
+    }
+}
+
+pub(crate) fn get_foreign_members(object: JsonObject) -> Result<Option<JsonObject>, Error> {
+    if object.is_empty() {
+        Ok(None)
+    } else {
+        Ok(Some(object))
+    }
+}